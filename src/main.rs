@@ -1,8 +1,10 @@
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    cursor,
+    event::{DisableMouseCapture, EnableMouseCapture, Event, EventStream, KeyCode, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use futures::StreamExt;
 use ratatui::{
     prelude::*,
     style::{Color, Modifier, Style},
@@ -10,80 +12,425 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Terminal,
 };
-use std::{error::Error, io};
+use std::{error::Error, io, panic, time::Duration};
+use tokio::time;
 use unicode_width::UnicodeWidthStr;
 use serde::{Deserialize, Serialize};
 use std::fs;
+
+/// How often the event loop wakes up on its own, independent of input, to
+/// redraw (e.g. to animate the help hint) and to flush any pending save.
+const TICK_RATE: Duration = Duration::from_millis(250);
 enum InputMode {
     Normal,
     Editing,
     TaskEditing,
+    Filter,
+    ListNaming,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct Todo {
     title: String,
     completed: bool,
 }
 
-struct App {
+#[derive(Serialize, Deserialize)]
+struct TodoList {
+    name: String,
     todos: Vec<Todo>,
+    // Undo/redo history is per-list, not per-app: an `Action` only makes
+    // sense replayed against the `todos` it was recorded against, so
+    // switching the active list must not touch another list's history.
+    // Ephemeral UI state, not persisted.
+    #[serde(skip)]
+    undo_stack: Vec<Action>,
+    #[serde(skip)]
+    redo_stack: Vec<Action>,
+}
+
+impl TodoList {
+    fn new(name: impl Into<String>) -> Self {
+        TodoList {
+            name: name.into(),
+            todos: Vec::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Board {
+    lists: Vec<TodoList>,
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Board {
+            lists: vec![TodoList::new("Todos")],
+        }
+    }
+}
+
+// A recorded mutation, pushed onto `undo_stack`/`redo_stack` so it can be
+// replayed in either direction. Applying one reverts the mutation it records
+// and yields the action recording that reversal, which is what makes undo
+// and redo share the same code path.
+enum Action {
+    Add { index: usize },
+    Delete { index: usize, todo: Todo },
+    Edit { index: usize, old_title: String },
+    Toggle { index: usize },
+}
+
+struct App {
+    board: Board,
+    active_list: usize,
     input: String,
     input_mode: InputMode,
     selected_index: Option<usize>,
     editing_task_index: Option<usize>,
+    // Indices into the active list's todos for the rows currently surviving
+    // the fuzzy filter, sorted by descending score. Only meaningful while
+    // `input_mode` is `Filter`; empty otherwise.
+    filtered_indices: Vec<usize>,
+    // How often the tick in `run_app` fires; also the flush interval for
+    // `dirty`.
+    tick_rate: Duration,
+    // Set whenever the board changes; cleared once a tick flushes it to
+    // disk, so saves are batched instead of happening on every keystroke.
+    dirty: bool,
+    // Flips on every tick so `ui()` can animate the Normal-mode help hint
+    // without relying on a blocking read.
+    blink: bool,
 }
 
 impl Default for App {
     fn default() -> App {
         App {
-            todos: App::load_todos(),
+            board: App::load_board(),
+            active_list: 0,
             input: String::new(),
             input_mode: InputMode::Normal,
             selected_index: None,
             editing_task_index: None,
+            filtered_indices: Vec::new(),
+            tick_rate: TICK_RATE,
+            dirty: false,
+            blink: false,
         }
     }
 }
 
 impl App {
-    fn save_todos(&self) -> io::Result<()> {
-        let json = serde_json::to_string(&self.todos)?;
+    fn save_board(&self) -> io::Result<()> {
+        let json = serde_json::to_string(&self.board)?;
         fs::write("todos.json", json)?;
         Ok(())
     }
 
-    fn load_todos() -> Vec<Todo> {
-        match fs::read_to_string("todos.json") {
-            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
-            Err(_) => Vec::new(),
+    /// Loads `todos.json`, falling back to `parse_board`'s defaults when the
+    /// file is missing or unreadable.
+    fn load_board() -> Board {
+        let Ok(json) = fs::read_to_string("todos.json") else {
+            return Board::default();
+        };
+        Self::parse_board(&json)
+    }
+
+    /// Parses a saved board, transparently wrapping a legacy bare
+    /// `Vec<Todo>` (the pre-board save format) into a single default list.
+    ///
+    /// `active_list` always indexes into a non-empty `lists`, so a board
+    /// with no lists at all (e.g. a hand-edited `{"lists":[]}`) is treated
+    /// the same as unparseable JSON.
+    fn parse_board(json: &str) -> Board {
+        if let Ok(board) = serde_json::from_str::<Board>(json) {
+            if !board.lists.is_empty() {
+                return board;
+            }
+        }
+        if let Ok(todos) = serde_json::from_str::<Vec<Todo>>(json) {
+            return Board {
+                lists: vec![TodoList {
+                    name: "Todos".to_string(),
+                    todos,
+                    undo_stack: Vec::new(),
+                    redo_stack: Vec::new(),
+                }],
+            };
+        }
+        Board::default()
+    }
+
+    /// The todos of the currently active list.
+    fn todos(&self) -> &Vec<Todo> {
+        &self.board.lists[self.active_list].todos
+    }
+
+    fn todos_mut(&mut self) -> &mut Vec<Todo> {
+        &mut self.board.lists[self.active_list].todos
+    }
+
+    /// The undo history of the currently active list.
+    fn undo_stack_mut(&mut self) -> &mut Vec<Action> {
+        &mut self.board.lists[self.active_list].undo_stack
+    }
+
+    /// The redo history of the currently active list.
+    fn redo_stack_mut(&mut self) -> &mut Vec<Action> {
+        &mut self.board.lists[self.active_list].redo_stack
+    }
+
+    /// Marks the board as having pending changes; the next tick flushes them.
+    fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Writes the board to disk if there are pending changes.
+    fn flush(&mut self) -> io::Result<()> {
+        if self.dirty {
+            self.save_board()?;
+            self.dirty = false;
+        }
+        Ok(())
+    }
+
+    fn is_filtering(&self) -> bool {
+        matches!(self.input_mode, InputMode::Filter)
+    }
+
+    /// Number of rows currently visible in the list: the filtered subset
+    /// while filtering, all of the active list's todos otherwise.
+    fn visible_len(&self) -> usize {
+        if self.is_filtering() {
+            self.filtered_indices.len()
+        } else {
+            self.todos().len()
+        }
+    }
+
+    /// Maps a row position in the visible list back to its index in the
+    /// active list's todos.
+    fn resolve_index(&self, visible_index: usize) -> Option<usize> {
+        if self.is_filtering() {
+            self.filtered_indices.get(visible_index).copied()
+        } else {
+            (visible_index < self.todos().len()).then_some(visible_index)
+        }
+    }
+
+    /// Re-scores every todo in the active list against the current query
+    /// (`input`) and rebuilds `filtered_indices`, best match first.
+    fn recompute_filter(&mut self) {
+        let mut scored: Vec<(usize, i32)> = self
+            .todos()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, todo)| fuzzy_score(&self.input, &todo.title).map(|score| (i, score)))
+            .collect();
+        scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        self.filtered_indices = scored.into_iter().map(|(i, _)| i).collect();
+    }
+
+    /// Keeps `selected_index` in bounds after the visible list changes size.
+    fn clamp_selected(&mut self) {
+        let len = self.visible_len();
+        self.selected_index = if len == 0 {
+            None
+        } else {
+            Some(self.selected_index.unwrap_or(0).min(len - 1))
+        };
+    }
+
+    /// Records `action` as undoable and invalidates any pending redo.
+    fn push_action(&mut self, action: Action) {
+        self.undo_stack_mut().push(action);
+        self.redo_stack_mut().clear();
+    }
+
+    /// Reverts the mutation recorded by `action` on the active list's todos
+    /// (e.g. `Add` removes the added todo) and returns the action recording
+    /// that reversal, so the caller can push it onto the opposite stack.
+    fn apply_action(&mut self, action: Action) -> Action {
+        match action {
+            Action::Add { index } => {
+                let todo = self.todos_mut().remove(index);
+                self.selected_index = Some(index.min(self.todos().len().saturating_sub(1)));
+                Action::Delete { index, todo }
+            }
+            Action::Delete { index, todo } => {
+                let insert_at = index.min(self.todos().len());
+                self.todos_mut().insert(insert_at, todo);
+                self.selected_index = Some(insert_at);
+                Action::Add { index: insert_at }
+            }
+            Action::Edit { index, old_title } => {
+                let reverted = std::mem::replace(&mut self.todos_mut()[index].title, old_title);
+                self.selected_index = Some(index);
+                Action::Edit {
+                    index,
+                    old_title: reverted,
+                }
+            }
+            Action::Toggle { index } => {
+                self.todos_mut()[index].completed = !self.todos()[index].completed;
+                self.selected_index = Some(index);
+                Action::Toggle { index }
+            }
+        }
+    }
+
+    /// Switches the active list, clearing filter state that's only valid
+    /// relative to the list being left. Undo/redo history lives on the
+    /// `TodoList` itself, so it's untouched by switching tabs.
+    fn switch_list(&mut self, new_index: usize) {
+        self.active_list = new_index;
+        self.selected_index = None;
+        self.filtered_indices.clear();
+    }
+
+    fn next_list(&mut self) {
+        let next = (self.active_list + 1) % self.board.lists.len();
+        self.switch_list(next);
+    }
+
+    fn prev_list(&mut self) {
+        let prev = (self.active_list + self.board.lists.len() - 1) % self.board.lists.len();
+        self.switch_list(prev);
+    }
+
+    /// Appends a new, empty list and switches to it.
+    fn add_list(&mut self, name: String) {
+        self.board.lists.push(TodoList::new(name));
+        self.switch_list(self.board.lists.len() - 1);
+        self.mark_dirty();
+    }
+
+    /// Moves the todo at `index` in the active list to the next list.
+    fn move_to_next_list(&mut self, index: usize) {
+        if self.board.lists.len() < 2 {
+            return;
+        }
+        let todo = self.todos_mut().remove(index);
+        let target = (self.active_list + 1) % self.board.lists.len();
+        self.board.lists[target].todos.push(todo);
+        // The remove() above shifted every later index in the active list,
+        // so any recorded action is now stale.
+        self.undo_stack_mut().clear();
+        self.redo_stack_mut().clear();
+        self.clamp_selected();
+        self.mark_dirty();
+    }
+
+    fn undo(&mut self) {
+        if let Some(action) = self.undo_stack_mut().pop() {
+            let inverse = self.apply_action(action);
+            self.redo_stack_mut().push(inverse);
+            self.clamp_selected();
+            self.mark_dirty();
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(action) = self.redo_stack_mut().pop() {
+            let inverse = self.apply_action(action);
+            self.undo_stack_mut().push(inverse);
+            self.clamp_selected();
+            self.mark_dirty();
         }
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
+/// Scores `candidate` against `query` as a fuzzy subsequence match: every
+/// character of `query` must appear in `candidate`, in order, but not
+/// necessarily contiguously. Returns `None` when `query` isn't a subsequence
+/// of `candidate`, otherwise `Some(score)` where a higher score means a
+/// tighter, more boundary-aligned match.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut score = 0i32;
+    let mut query_pos = 0usize;
+    let mut last_match: Option<usize> = None;
+
+    for (i, &c) in candidate_chars.iter().enumerate() {
+        if query_pos >= query_chars.len() {
+            break;
+        }
+        if c == query_chars[query_pos] {
+            score += 1;
+            if last_match == Some(i.wrapping_sub(1)) {
+                score += 5;
+            }
+            let at_boundary = i == 0
+                || matches!(candidate_chars[i - 1], ' ' | '_' | '-' | '.');
+            if at_boundary {
+                score += 3;
+            }
+            last_match = Some(i);
+            query_pos += 1;
+        } else if last_match.is_some() {
+            score -= 1;
+        }
+    }
+
+    (query_pos == query_chars.len()).then_some(score)
+}
+
+/// Puts the terminal into raw/alternate-screen mode on construction and
+/// restores it on drop, so a panic unwinding out of `run_app`/`ui` still
+/// leaves the user's shell usable instead of stuck in raw mode.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn new() -> io::Result<Self> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            cursor::Show
+        );
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |panic_info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            cursor::Show
+        );
+        default_hook(panic_info);
+    }));
+
+    let guard = TerminalGuard::new()?;
+    let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend)?;
 
-    let app = App {
-        todos: App::load_todos(),
-        input: String::new(),
-        input_mode: InputMode::Normal,
-        selected_index: None,
-        editing_task_index: None,
-    };
-    let res = run_app(&mut terminal, app);
+    let app = App::default();
+    let res = run_app(&mut terminal, app).await;
 
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    drop(guard);
 
     if let Err(err) = res {
         println!("{:?}", err)
@@ -92,31 +439,88 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<()> {
+async fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<()> {
+    let mut events = EventStream::new();
+    let mut tick = time::interval(app.tick_rate);
+
     loop {
         terminal.draw(|f| ui(f, &app))?;
 
-        if let Event::Key(key) = event::read()? {
+        let key = tokio::select! {
+            _ = tick.tick() => {
+                app.blink = !app.blink;
+                app.flush()?;
+                continue;
+            }
+            maybe_event = events.next() => match maybe_event {
+                Some(Ok(Event::Resize(_, _))) => {
+                    terminal.clear()?;
+                    continue;
+                }
+                Some(Ok(Event::Key(key))) => key,
+                Some(Ok(_)) => continue,
+                Some(Err(err)) => {
+                    app.flush()?;
+                    return Err(err);
+                }
+                None => {
+                    app.flush()?;
+                    return Ok(());
+                }
+            },
+        };
+
+        {
             match app.input_mode {
                 InputMode::Normal => match key.code {
                     KeyCode::Char('e') => {
                         app.input_mode = InputMode::Editing;
                     }
+                    KeyCode::Char('/') => {
+                        app.input.clear();
+                        app.input_mode = InputMode::Filter;
+                        app.recompute_filter();
+                        app.clamp_selected();
+                    }
                     KeyCode::Enter => {
-                        if let Some(index) = app.selected_index {
-                            if index < app.todos.len() {
-                                app.input = app.todos[index].title.clone();
+                        if let Some(visible) = app.selected_index {
+                            if let Some(index) = app.resolve_index(visible) {
+                                app.input = app.todos()[index].title.clone();
                                 app.editing_task_index = Some(index);
                                 app.input_mode = InputMode::TaskEditing;
                             }
                         }
                     }
                     KeyCode::Char('q') => {
+                        app.flush()?;
                         return Ok(());
                     }
+                    KeyCode::Char('u') => {
+                        app.undo();
+                    }
+                    KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app.redo();
+                    }
+                    KeyCode::Tab => {
+                        app.next_list();
+                    }
+                    KeyCode::BackTab => {
+                        app.prev_list();
+                    }
+                    KeyCode::Char('N') => {
+                        app.input.clear();
+                        app.input_mode = InputMode::ListNaming;
+                    }
+                    KeyCode::Char('m') => {
+                        if let Some(visible) = app.selected_index {
+                            if let Some(index) = app.resolve_index(visible) {
+                                app.move_to_next_list(index);
+                            }
+                        }
+                    }
                     KeyCode::Char('j') | KeyCode::Down => {
                         if let Some(index) = app.selected_index {
-                            if index < app.todos.len().saturating_sub(1) {
+                            if index < app.todos().len().saturating_sub(1) {
                                 app.selected_index = Some(index + 1);
                             }
                         } else {
@@ -131,21 +535,23 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
                         }
                     }
                     KeyCode::Char(' ') => {
-                        if let Some(index) = app.selected_index {
-                            if index < app.todos.len() {
-                                app.todos[index].completed = !app.todos[index].completed;
-                                app.save_todos()?;
+                        if let Some(visible) = app.selected_index {
+                            if let Some(index) = app.resolve_index(visible) {
+                                app.todos_mut()[index].completed = !app.todos()[index].completed;
+                                app.push_action(Action::Toggle { index });
+                                app.mark_dirty();
                             }
                         }
                     }
                     KeyCode::Delete | KeyCode::Backspace => {
-                        if let Some(index) = app.selected_index {
-                            if index < app.todos.len() {
-                                app.todos.remove(index);
-                                app.save_todos()?;
-                                if app.todos.is_empty() {
+                        if let Some(visible) = app.selected_index {
+                            if let Some(index) = app.resolve_index(visible) {
+                                let todo = app.todos_mut().remove(index);
+                                app.push_action(Action::Delete { index, todo });
+                                app.mark_dirty();
+                                if app.todos().is_empty() {
                                     app.selected_index = None;
-                                } else if index == app.todos.len() {
+                                } else if index == app.todos().len() {
                                     app.selected_index = Some(index - 1);
                                 }
                             }
@@ -153,12 +559,88 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
                     }
                     _ => {}
                 },
+                InputMode::Filter => match key.code {
+                    // Spec deviation: letters (including 'j'/'k'/space) are
+                    // reserved for query text, so navigation moves to the
+                    // arrow keys and toggle to Tab instead of staying put.
+                    KeyCode::Esc => {
+                        // selected_index is a row into filtered_indices while
+                        // filtering; resolve it to a real todos index before
+                        // dropping filtered_indices, or Normal mode will
+                        // reinterpret the same number as a raw todos index
+                        // and select the wrong row.
+                        app.selected_index = app.selected_index.and_then(|visible| app.resolve_index(visible));
+                        app.input.clear();
+                        app.filtered_indices.clear();
+                        app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Enter => {
+                        if let Some(visible) = app.selected_index {
+                            if let Some(index) = app.resolve_index(visible) {
+                                app.input = app.todos()[index].title.clone();
+                                app.editing_task_index = Some(index);
+                                app.selected_index = Some(index);
+                                app.filtered_indices.clear();
+                                app.input_mode = InputMode::TaskEditing;
+                            }
+                        }
+                    }
+                    KeyCode::Down => {
+                        if let Some(visible) = app.selected_index {
+                            if visible < app.filtered_indices.len().saturating_sub(1) {
+                                app.selected_index = Some(visible + 1);
+                            }
+                        } else if !app.filtered_indices.is_empty() {
+                            app.selected_index = Some(0);
+                        }
+                    }
+                    KeyCode::Up => {
+                        if let Some(visible) = app.selected_index {
+                            if visible > 0 {
+                                app.selected_index = Some(visible - 1);
+                            }
+                        }
+                    }
+                    KeyCode::Tab => {
+                        if let Some(visible) = app.selected_index {
+                            if let Some(index) = app.resolve_index(visible) {
+                                app.todos_mut()[index].completed = !app.todos()[index].completed;
+                                app.push_action(Action::Toggle { index });
+                                app.mark_dirty();
+                            }
+                        }
+                    }
+                    KeyCode::Delete => {
+                        if let Some(visible) = app.selected_index {
+                            if let Some(index) = app.resolve_index(visible) {
+                                let todo = app.todos_mut().remove(index);
+                                app.push_action(Action::Delete { index, todo });
+                                app.mark_dirty();
+                                app.recompute_filter();
+                                app.clamp_selected();
+                            }
+                        }
+                    }
+                    KeyCode::Char(c) => {
+                        app.input.push(c);
+                        app.recompute_filter();
+                        app.clamp_selected();
+                    }
+                    KeyCode::Backspace => {
+                        app.input.pop();
+                        app.recompute_filter();
+                        app.clamp_selected();
+                    }
+                    _ => {}
+                },
                 InputMode::TaskEditing => match key.code {
                     KeyCode::Enter => {
                         if let Some(index) = app.editing_task_index {
                             if !app.input.is_empty() {
-                                app.todos[index].title = app.input.drain(..).collect();
-                                app.save_todos()?;
+                                let old_title = app.todos()[index].title.clone();
+                                app.todos_mut()[index].title = app.input.drain(..).collect();
+                                app.push_action(Action::Edit { index, old_title });
+                                app.mark_dirty();
                             }
                             app.editing_task_index = None;
                             app.input_mode = InputMode::Normal;
@@ -180,11 +662,15 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
                 InputMode::Editing => match key.code {
                     KeyCode::Enter => {
                         if !app.input.is_empty() {
-                            app.todos.push(Todo {
-                                title: app.input.drain(..).collect(),
+                            let title = app.input.drain(..).collect();
+                            app.todos_mut().push(Todo {
+                                title,
                                 completed: false,
                             });
-                            app.save_todos()?;
+                            app.push_action(Action::Add {
+                                index: app.todos().len() - 1,
+                            });
+                            app.mark_dirty();
                             if app.selected_index.is_none() {
                                 app.selected_index = Some(0);
                             }
@@ -202,6 +688,26 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
                     }
                     _ => {}
                 },
+                InputMode::ListNaming => match key.code {
+                    KeyCode::Enter => {
+                        if !app.input.is_empty() {
+                            let name = app.input.drain(..).collect();
+                            app.add_list(name);
+                        }
+                        app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char(c) => {
+                        app.input.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        app.input.pop();
+                    }
+                    KeyCode::Esc => {
+                        app.input.clear();
+                        app.input_mode = InputMode::Normal;
+                    }
+                    _ => {}
+                },
             }
         }
     }
@@ -214,6 +720,7 @@ fn ui(f: &mut Frame, app: &App) {
         .constraints(
             [
                 Constraint::Length(3),
+                Constraint::Length(1),
                 Constraint::Min(0),
                 Constraint::Length(3),
             ]
@@ -231,10 +738,22 @@ fn ui(f: &mut Frame, app: &App) {
                     Span::styled("e", Style::default().add_modifier(Modifier::BOLD)),
                     Span::raw(" to start editing, "),
                     Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(" to edit selected task."),
+                    Span::raw(" to edit selected task, "),
+                    Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw("/"),
+                    Span::styled("Shift-Tab", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to switch list, "),
+                    Span::styled("N", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to create a list, "),
+                    Span::styled("m", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to move task to the next list."),
                 ]),
             ],
-            Style::default().add_modifier(Modifier::RAPID_BLINK),
+            if app.blink {
+                Style::default().add_modifier(Modifier::RAPID_BLINK)
+            } else {
+                Style::default()
+            },
         ),
         InputMode::TaskEditing => (
             vec![
@@ -248,6 +767,22 @@ fn ui(f: &mut Frame, app: &App) {
             ],
             Style::default(),
         ),
+        InputMode::Filter => (
+            vec![
+                Line::from(vec![
+                    Span::raw("Type to fuzzy-filter, "),
+                    Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to move, "),
+                    Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to toggle, "),
+                    Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to edit, "),
+                    Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to cancel"),
+                ]),
+            ],
+            Style::default(),
+        ),
         InputMode::Editing => (
             vec![
                 Line::from(vec![
@@ -260,6 +795,18 @@ fn ui(f: &mut Frame, app: &App) {
             ],
             Style::default(),
         ),
+        InputMode::ListNaming => (
+            vec![
+                Line::from(vec![
+                    Span::raw("Type a name, "),
+                    Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to create the list, "),
+                    Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(" to cancel"),
+                ]),
+            ],
+            Style::default(),
+        ),
     };
 
     let help_message = Paragraph::new(msg)
@@ -267,20 +814,57 @@ fn ui(f: &mut Frame, app: &App) {
         .block(Block::default().borders(Borders::ALL).title("Help"));
     f.render_widget(help_message, chunks[0]);
 
+    let tabs: Vec<Span> = app
+        .board
+        .lists
+        .iter()
+        .enumerate()
+        .flat_map(|(i, list)| {
+            let style = if i == app.active_list {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            [Span::styled(format!(" {} ", list.name), style), Span::raw(" ")]
+        })
+        .collect();
+    f.render_widget(Paragraph::new(Line::from(tabs)), chunks[1]);
+
     let input = Paragraph::new(vec![Line::from(app.input.as_str())])
         .style(match app.input_mode {
             InputMode::Normal => Style::default(),
             InputMode::Editing => Style::default().fg(Color::Yellow),
             InputMode::TaskEditing => Style::default().fg(Color::Green),
+            InputMode::Filter => Style::default().fg(Color::Cyan),
+            InputMode::ListNaming => Style::default().fg(Color::Magenta),
         })
-        .block(Block::default().borders(Borders::ALL).title("Input"));
-    f.render_widget(input, chunks[2]);
+        .block(Block::default().borders(Borders::ALL).title(
+            if app.is_filtering() {
+                "Filter"
+            } else if matches!(app.input_mode, InputMode::ListNaming) {
+                "New list name"
+            } else {
+                "Input"
+            },
+        ));
+    f.render_widget(input, chunks[3]);
 
-    let todos: Vec<ListItem> = app
-        .todos
+    let visible_todos: Vec<(usize, &Todo)> = if app.is_filtering() {
+        app.filtered_indices
+            .iter()
+            .map(|&i| (i, &app.todos()[i]))
+            .collect()
+    } else {
+        app.todos().iter().enumerate().collect()
+    };
+
+    let todos: Vec<ListItem> = visible_todos
         .iter()
         .enumerate()
-        .map(|(i, todo)| {
+        .map(|(row, (_, todo))| {
             let content = vec![Line::from(vec![
                 Span::styled(
                     if todo.completed {
@@ -294,9 +878,9 @@ fn ui(f: &mut Frame, app: &App) {
                         Color::White
                     }),
                 ),
-                Span::raw(&todo.title),
+                Span::raw(todo.title.as_str()),
             ])];
-            ListItem::new(content).style(Style::default().fg(if Some(i) == app.selected_index {
+            ListItem::new(content).style(Style::default().fg(if Some(row) == app.selected_index {
                 Color::Yellow
             } else {
                 Color::White
@@ -304,23 +888,115 @@ fn ui(f: &mut Frame, app: &App) {
         })
         .collect();
 
+    let active_name = &app.board.lists[app.active_list].name;
+    let list_title = if app.is_filtering() {
+        format!(
+            "{} ({}/{})",
+            active_name,
+            app.filtered_indices.len(),
+            app.todos().len()
+        )
+    } else {
+        active_name.clone()
+    };
     let todos = List::new(todos)
-        .block(Block::default().borders(Borders::ALL).title("To-Do List"))
+        .block(Block::default().borders(Borders::ALL).title(list_title))
         .highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
         );
 
-    f.render_widget(todos, chunks[1]);
-    
+    f.render_widget(todos, chunks[2]);
+
     match app.input_mode {
         InputMode::Normal => {}
-        InputMode::TaskEditing | InputMode::Editing => {
+        InputMode::TaskEditing | InputMode::Editing | InputMode::Filter | InputMode::ListNaming => {
             f.set_cursor(
-                chunks[2].x + app.input.width() as u16 + 1,
-                chunks[2].y + 1,
+                chunks[3].x + app.input.width() as u16 + 1,
+                chunks[3].y + 1,
             )
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_score_matches_subsequence() {
+        assert!(fuzzy_score("brd", "board").is_some());
+    }
+
+    #[test]
+    fn fuzzy_score_rejects_out_of_order_chars() {
+        assert!(fuzzy_score("drb", "board").is_none());
+    }
+
+    #[test]
+    fn fuzzy_score_rejects_non_subsequence() {
+        assert!(fuzzy_score("xyz", "board").is_none());
+    }
+
+    #[test]
+    fn fuzzy_score_empty_query_matches_everything() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn fuzzy_score_is_case_insensitive() {
+        assert!(fuzzy_score("BRD", "board").is_some());
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_consecutive_matches_over_scattered_ones() {
+        let consecutive = fuzzy_score("bo", "board").unwrap();
+        let scattered = fuzzy_score("bd", "board").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_word_boundary_matches_over_mid_word_ones() {
+        let boundary = fuzzy_score("w", "do work").unwrap();
+        let mid_word = fuzzy_score("o", "do work").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn fuzzy_score_penalizes_gaps_between_matches() {
+        let tight = fuzzy_score("ac", "abc").unwrap();
+        let gappy = fuzzy_score("ac", "abbc").unwrap();
+        assert!(tight > gappy);
+    }
+
+    #[test]
+    fn parse_board_reads_current_format() {
+        let board = App::parse_board(r#"{"lists":[{"name":"Work","todos":[]}]}"#);
+        assert_eq!(board.lists.len(), 1);
+        assert_eq!(board.lists[0].name, "Work");
+    }
+
+    #[test]
+    fn parse_board_falls_back_to_legacy_bare_list() {
+        let board = App::parse_board(r#"[{"title":"a","completed":false}]"#);
+        assert_eq!(board.lists.len(), 1);
+        assert_eq!(board.lists[0].name, "Todos");
+        assert_eq!(board.lists[0].todos.len(), 1);
+        assert_eq!(board.lists[0].todos[0].title, "a");
+    }
+
+    #[test]
+    fn parse_board_rejects_a_board_with_no_lists() {
+        let board = App::parse_board(r#"{"lists":[]}"#);
+        assert_eq!(board.lists.len(), 1);
+        assert_eq!(board.lists[0].name, "Todos");
+    }
+
+    #[test]
+    fn parse_board_falls_back_to_default_on_garbage() {
+        let board = App::parse_board("not json");
+        assert_eq!(board.lists.len(), 1);
+        assert_eq!(board.lists[0].name, "Todos");
+    }
+}